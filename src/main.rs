@@ -1,11 +1,28 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::time::Instant;
 
 pub mod colony;
 pub mod simulation;
 pub mod parser;
+pub mod router;
 
-use simulation::Simulation;
+use simulation::{MovementStrategy, Simulation};
+
+/// Movement strategy selectable from the command line.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum StrategyArg {
+    Random,
+    Pursuit,
+}
+
+impl From<StrategyArg> for MovementStrategy {
+    fn from(arg: StrategyArg) -> Self {
+        match arg {
+            StrategyArg::Random => MovementStrategy::Random,
+            StrategyArg::Pursuit => MovementStrategy::Pursuit,
+        }
+    }
+}
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -15,25 +32,62 @@ struct Args {
     ants: usize,
 
     /// Path to the map file
-    #[arg(short, long)]
-    map: String,
+    #[arg(short, long, conflicts_with = "regex_map")]
+    map: Option<String>,
+
+    /// Direction-regex maze description to build colonies from, e.g. "^N(E|W)S$"
+    #[arg(long, conflicts_with = "map")]
+    regex_map: Option<String>,
+
+    /// Print the shortest colony-name path between FROM and TO and exit, e.g. "--route Ant,Bee"
+    #[arg(long, value_name = "FROM,TO")]
+    route: Option<String>,
+
+    /// Movement strategy ants use to pick their next tunnel
+    #[arg(long, value_enum, default_value = "random")]
+    strategy: StrategyArg,
+
+    /// RNG seed to use, for reproducing a previous run bit-for-bit
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Force the parallel per-ant collection phase, even below its ant-count threshold
+    #[arg(long)]
+    parallel: bool,
 }
 
 fn main() {
     let args = Args::parse();
-    
-    // Read and parse the map file
-    let colonies = parser::parse_map_file(&args.map).expect("Failed to parse map file");
-    
+
+    // Read and parse the map, either from a map file or a regex description
+    let colonies = match (&args.map, &args.regex_map) {
+        (Some(map), None) => parser::parse_map_file(map).expect("Failed to parse map file"),
+        (None, Some(regex_map)) => parser::parse_regex_map(regex_map),
+        _ => panic!("exactly one of --map or --regex-map must be given"),
+    };
+
+    if let Some(route) = &args.route {
+        print_route(&colonies, route);
+        return;
+    }
+
     // Create and run simulation
-    let mut simulation = Simulation::new(colonies, args.ants);
-    
+    let mut simulation = match args.seed {
+        Some(seed) => Simulation::new_with_seed(colonies, args.ants, seed),
+        None => Simulation::new(colonies, args.ants),
+    }
+    .expect("Failed to create simulation");
+    simulation.set_strategy(args.strategy.into());
+    simulation.set_parallel(args.parallel);
+    println!("Using seed: {}", simulation.seed());
+    println!("Parallel collection phase: {}", if simulation.is_parallel_active() { "active" } else { "inactive" });
+
     // Start timing after map is loaded
     let start_time = Instant::now();
-    
+
     // Run the simulation
-    simulation.run();
-    
+    simulation.run().expect("Simulation failed");
+
     // Calculate and print execution time
     let duration = start_time.elapsed();
     println!("\nSimulation completed in {:?}", duration);
@@ -41,3 +95,28 @@ fn main() {
     // Print final state
     simulation.print_final_state();
 }
+
+/// Resolves a "FROM,TO" colony-name pair and prints the shortest path between them.
+fn print_route(colonies: &[colony::Colony], route: &str) {
+    let (from_name, to_name) = route
+        .split_once(',')
+        .expect("--route expects a FROM,TO pair of colony names");
+
+    let start = colonies
+        .iter()
+        .position(|c| c.name == from_name)
+        .unwrap_or_else(|| panic!("unknown colony '{}'", from_name));
+    let goal = colonies
+        .iter()
+        .position(|c| c.name == to_name)
+        .unwrap_or_else(|| panic!("unknown colony '{}'", to_name));
+
+    let destroyed = vec![false; colonies.len()];
+    match router::shortest_path(colonies, &destroyed, start, goal) {
+        Some(path) => {
+            let names: Vec<&str> = path.iter().map(|&idx| colonies[idx].name.as_str()).collect();
+            println!("{}", names.join(" -> "));
+        }
+        None => println!("No path from {} to {}", from_name, to_name),
+    }
+}
@@ -4,6 +4,9 @@ use std::io::{self, BufRead};
 use std::path::Path;
 use crate::colony::{Colony, Direction};
 
+/// Grid coordinate used to key auto-generated colonies while walking a regex map.
+type Coord = (i32, i32);
+
 pub fn parse_map_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<Colony>> {
     let file = File::open(&path)?;
     let reader = io::BufReader::new(file);
@@ -48,4 +51,156 @@ pub fn parse_map_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<Colony>> {
     }
 
     Ok(colonies)
+}
+
+/// Builds a colony graph from a direction-regex maze description, e.g. `^N(E|W)S$`.
+///
+/// `N`/`S`/`E`/`W` step every colony in the current position set to a
+/// neighboring colony, creating it (and the reciprocal tunnel) on first
+/// visit. `(` opens a set of alternative branches, `|` separates them, and
+/// `)` rejoins all branch endpoints into the new current position set.
+/// `^` and `$` anchors are accepted and ignored.
+///
+/// The current position set, the branch stack, and merged branch endpoints
+/// are all sorted and deduplicated after every step, so branches that
+/// rejoin at the same colony (e.g. `(ES|SE)`) don't double the set size on
+/// every subsequent alternation.
+pub fn parse_regex_map(input: &str) -> Vec<Colony> {
+    let mut colonies = Vec::new();
+    let mut coord_to_idx: HashMap<Coord, usize> = HashMap::new();
+    let mut idx_to_coord: Vec<Coord> = Vec::new();
+
+    let start_coord = (0, 0);
+    let start_idx = get_or_create_colony(&mut colonies, &mut coord_to_idx, &mut idx_to_coord, start_coord);
+
+    let mut current = vec![start_idx];
+    // Each open group keeps its start set and the end positions of its finished branches.
+    let mut stack: Vec<(Vec<usize>, Vec<usize>)> = Vec::new();
+
+    for ch in input.chars() {
+        match ch {
+            '^' | '$' => {}
+            'N' | 'S' | 'E' | 'W' => {
+                let (direction, dx, dy) = match ch {
+                    'N' => (Direction::North, 0, 1),
+                    'S' => (Direction::South, 0, -1),
+                    'E' => (Direction::East, 1, 0),
+                    'W' => (Direction::West, -1, 0),
+                    _ => unreachable!(),
+                };
+                let opposite = opposite_direction(direction);
+
+                let mut next = Vec::with_capacity(current.len());
+                for &idx in &current {
+                    let (x, y) = idx_to_coord[idx];
+                    let target_coord = (x + dx, y + dy);
+                    let target_idx = get_or_create_colony(&mut colonies, &mut coord_to_idx, &mut idx_to_coord, target_coord);
+                    if colonies[idx].get_target_colony(&direction).is_none() {
+                        colonies[idx].add_tunnel(direction, target_idx);
+                        colonies[target_idx].add_tunnel(opposite, idx);
+                    }
+                    next.push(target_idx);
+                }
+                current = dedup_sorted(next);
+            }
+            '(' => {
+                stack.push((current.clone(), Vec::new()));
+            }
+            '|' => {
+                let (start, ends) = stack.last_mut().expect("'|' without matching '('");
+                ends.extend(&current);
+                current = start.clone();
+            }
+            ')' => {
+                let (_, mut ends) = stack.pop().expect("')' without matching '('");
+                ends.extend(&current);
+                current = dedup_sorted(ends);
+            }
+            _ => {}
+        }
+    }
+
+    colonies
+}
+
+/// Sorts and deduplicates a position set so repeated merge points don't
+/// accumulate duplicate entries across alternations.
+fn dedup_sorted(mut positions: Vec<usize>) -> Vec<usize> {
+    positions.sort_unstable();
+    positions.dedup();
+    positions
+}
+
+/// Returns the existing colony at `coord`, or creates one with an
+/// auto-generated coordinate-based name.
+fn get_or_create_colony(
+    colonies: &mut Vec<Colony>,
+    coord_to_idx: &mut HashMap<Coord, usize>,
+    idx_to_coord: &mut Vec<Coord>,
+    coord: Coord,
+) -> usize {
+    *coord_to_idx.entry(coord).or_insert_with(|| {
+        colonies.push(Colony::new(format!("{}_{}", coord.0, coord.1)));
+        idx_to_coord.push(coord);
+        colonies.len() - 1
+    })
+}
+
+fn opposite_direction(direction: Direction) -> Direction {
+    match direction {
+        Direction::North => Direction::South,
+        Direction::South => Direction::North,
+        Direction::East => Direction::West,
+        Direction::West => Direction::East,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(colonies: &'a [Colony], name: &str) -> &'a Colony {
+        colonies.iter().find(|c| c.name == name).unwrap_or_else(|| panic!("missing colony '{}'", name))
+    }
+
+    fn idx_of(colonies: &[Colony], name: &str) -> usize {
+        colonies.iter().position(|c| c.name == name).unwrap_or_else(|| panic!("missing colony '{}'", name))
+    }
+
+    #[test]
+    fn parse_regex_map_builds_the_doc_comment_example() {
+        // "^N(E|W)S$": start -> N -> branch east/west -> S, six distinct colonies total.
+        let colonies = parse_regex_map("^N(E|W)S$");
+        assert_eq!(colonies.len(), 6);
+
+        let start = idx_of(&colonies, "0_0");
+        let north = idx_of(&colonies, "0_1");
+        let east = idx_of(&colonies, "1_1");
+        let west = idx_of(&colonies, "-1_1");
+        let south_of_east = idx_of(&colonies, "1_0");
+        let south_of_west = idx_of(&colonies, "-1_0");
+
+        assert_eq!(find(&colonies, "0_0").get_target_colony(&Direction::North), Some(north));
+        assert_eq!(find(&colonies, "0_1").get_target_colony(&Direction::South), Some(start));
+        assert_eq!(find(&colonies, "0_1").get_target_colony(&Direction::East), Some(east));
+        assert_eq!(find(&colonies, "0_1").get_target_colony(&Direction::West), Some(west));
+        assert_eq!(find(&colonies, "1_1").get_target_colony(&Direction::West), Some(north));
+        assert_eq!(find(&colonies, "1_1").get_target_colony(&Direction::South), Some(south_of_east));
+        assert_eq!(find(&colonies, "-1_1").get_target_colony(&Direction::East), Some(north));
+        assert_eq!(find(&colonies, "-1_1").get_target_colony(&Direction::South), Some(south_of_west));
+        assert_eq!(find(&colonies, "1_0").get_target_colony(&Direction::North), Some(east));
+        assert_eq!(find(&colonies, "-1_0").get_target_colony(&Direction::North), Some(west));
+    }
+
+    #[test]
+    fn parse_regex_map_dedups_branches_that_rejoin_at_the_same_colony() {
+        // Each "(ES|SE)" repeat adds exactly 3 new colonies (both arms' own
+        // intermediate hop plus the merge point they share), no matter how
+        // many repeats precede it, since colonies are keyed by coordinate and
+        // the shared merge point is created only once.
+        let n = 8;
+        let pattern = format!("^{}$", "(ES|SE)".repeat(n));
+        let colonies = parse_regex_map(&pattern);
+        assert_eq!(colonies.len(), 3 * n + 1);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+
+use crate::colony::{Colony, Direction};
+
+/// Finds the shortest path between two colonies via breadth-first search.
+///
+/// Every tunnel has unit cost, so a plain BFS over the non-destroyed colony
+/// graph is sufficient to find a shortest path.
+///
+/// # Arguments
+/// * `colonies` - Vector of colonies
+/// * `destroyed` - Bit vector marking colonies to treat as unreachable
+/// * `start` - Index of the starting colony
+/// * `goal` - Index of the destination colony
+///
+/// # Returns
+/// * `Some(Vec<usize>)` - Colony indices from `start` to `goal`, inclusive
+/// * `None` - If `goal` is unreachable from `start`
+pub fn shortest_path(
+    colonies: &[Colony],
+    destroyed: &[bool],
+    start: usize,
+    goal: usize,
+) -> Option<Vec<usize>> {
+    if destroyed[start] || destroyed[goal] {
+        return None;
+    }
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    let mut visited = vec![false; colonies.len()];
+    let mut parent: Vec<Option<usize>> = vec![None; colonies.len()];
+    let mut frontier = VecDeque::new();
+
+    visited[start] = true;
+    frontier.push_back(start);
+
+    while let Some(current) = frontier.pop_front() {
+        if current == goal {
+            return Some(reconstruct_path(&parent, start, goal));
+        }
+
+        for direction in [Direction::North, Direction::South, Direction::East, Direction::West] {
+            if let Some(target_idx) = colonies[current].get_target_colony(&direction) {
+                if destroyed[target_idx] || visited[target_idx] {
+                    continue;
+                }
+                visited[target_idx] = true;
+                parent[target_idx] = Some(current);
+                frontier.push_back(target_idx);
+            }
+        }
+    }
+
+    None
+}
+
+/// Walks `parent` back from `goal` to `start` and reverses it into a forward path.
+fn reconstruct_path(parent: &[Option<usize>], start: usize, goal: usize) -> Vec<usize> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while current != start {
+        current = parent[current].expect("goal was reached so a parent chain to start must exist");
+        path.push(current);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(names: &[&str]) -> Vec<Colony> {
+        let mut colonies: Vec<Colony> = names.iter().map(|n| Colony::new(n.to_string())).collect();
+        for i in 0..colonies.len() - 1 {
+            colonies[i].add_tunnel(Direction::East, i + 1);
+            colonies[i + 1].add_tunnel(Direction::West, i);
+        }
+        colonies
+    }
+
+    #[test]
+    fn finds_the_shortest_path_along_a_chain_of_tunnels() {
+        let colonies = chain(&["A", "B", "C", "D"]);
+        let destroyed = vec![false; colonies.len()];
+
+        let path = shortest_path(&colonies, &destroyed, 0, 3);
+
+        assert_eq!(path, Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn returns_none_when_the_goal_is_behind_a_destroyed_colony() {
+        let colonies = chain(&["A", "B", "C", "D"]);
+        let mut destroyed = vec![false; colonies.len()];
+        destroyed[2] = true;
+
+        let path = shortest_path(&colonies, &destroyed, 0, 3);
+
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn a_colony_is_trivially_reachable_from_itself() {
+        let colonies = chain(&["A", "B", "C"]);
+        let destroyed = vec![false; colonies.len()];
+
+        let path = shortest_path(&colonies, &destroyed, 1, 1);
+
+        assert_eq!(path, Some(vec![1]));
+    }
+}
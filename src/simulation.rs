@@ -2,12 +2,16 @@ use rand::Rng;
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 use rand::rng;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, VecDeque};
 use crate::colony::{Colony, Direction};
 
 /// Maximum number of moves allowed per ant
 const MAX_MOVES: u32 = 10_000;
 /// Maximum number of steps allowed in the simulation
 const MAX_STEPS: u32 = 100_000;
+/// Ant count at or above which the per-ant collection phase parallelizes automatically
+const PARALLEL_ANT_THRESHOLD: usize = 1_000;
 
 /// Error type for simulation errors
 #[derive(Debug)]
@@ -17,6 +21,24 @@ pub enum SimulationError {
     InvalidColony(usize),
 }
 
+/// Selects how an ant picks its next tunnel each step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovementStrategy {
+    /// Move through a uniformly random available tunnel.
+    Random,
+    /// Move one step along the shortest path toward the nearest colony
+    /// occupied by another ant, falling back to `Random` if none is reachable.
+    Pursuit,
+}
+
+/// Result of evaluating a single ant's move during the read phase of `step`.
+enum AntOutcome {
+    /// Ant `.0` moves from colony `.1` to the empty colony `.2`.
+    Move(usize, usize, usize),
+    /// Ant `.1` and resident ant `.2` fight over colony `.0`.
+    Fight(usize, usize, usize),
+}
+
 #[derive(Clone)]
 struct Ant {
     moves: u32,
@@ -52,6 +74,12 @@ pub struct Simulation {
     debug: bool,
     /// RNG instance for the simulation
     rng: SmallRng,
+    /// Movement strategy used to pick each ant's next tunnel
+    strategy: MovementStrategy,
+    /// Seed the simulation's RNG was initialized with, so a run can be replayed
+    seed: u64,
+    /// Whether to force the parallel collection phase regardless of ant count
+    parallel: bool,
 }
 
 impl Simulation {
@@ -67,7 +95,23 @@ impl Simulation {
     /// # Errors
     /// * `SimulationError::NoColonies` - If no colonies are provided
     /// * `SimulationError::NoAnts` - If num_ants is 0
-    pub fn new(mut colonies: Vec<Colony>, num_ants: usize) -> Result<Self, SimulationError> {
+    pub fn new(colonies: Vec<Colony>, num_ants: usize) -> Result<Self, SimulationError> {
+        let seed = rng().random();
+        Self::new_with_seed(colonies, num_ants, seed)
+    }
+
+    /// Creates a new simulation seeded deterministically, so a run can be
+    /// replayed bit-for-bit by passing the same `seed` again.
+    ///
+    /// # Arguments
+    /// * `colonies` - Vector of colonies
+    /// * `num_ants` - Number of ants to create
+    /// * `seed` - Seed for the simulation's `SmallRng`
+    ///
+    /// # Errors
+    /// * `SimulationError::NoColonies` - If no colonies are provided
+    /// * `SimulationError::NoAnts` - If num_ants is 0
+    pub fn new_with_seed(mut colonies: Vec<Colony>, num_ants: usize, seed: u64) -> Result<Self, SimulationError> {
         if colonies.is_empty() {
             return Err(SimulationError::NoColonies);
         }
@@ -75,7 +119,6 @@ impl Simulation {
             return Err(SimulationError::NoAnts);
         }
 
-        let seed = rng().random();
         let mut rng = SmallRng::seed_from_u64(seed);
         let mut ants = Vec::with_capacity(num_ants);
         let destroyed_colonies = vec![false; colonies.len()];
@@ -107,6 +150,9 @@ impl Simulation {
             max_steps: MAX_STEPS,
             debug: true,
             rng,
+            strategy: MovementStrategy::Random,
+            seed,
+            parallel: false,
         })
     }
 
@@ -117,6 +163,46 @@ impl Simulation {
         Ok(sim)
     }
 
+    /// Sets the movement strategy ants use to pick their next tunnel.
+    pub fn set_strategy(&mut self, strategy: MovementStrategy) {
+        self.strategy = strategy;
+    }
+
+    /// Returns the seed the simulation's RNG was initialized with.
+    ///
+    /// Replaying this seed reproduces a run bit-for-bit only while the
+    /// collection phase stays on the same path (serial or parallel) it ran
+    /// on originally: the parallel path draws each ant's randomness from a
+    /// per-ant RNG derived from the seed instead of the single shared
+    /// `SmallRng` the serial path advances, so the same seed can still
+    /// diverge into different outcomes across the two paths. See
+    /// [`Self::set_parallel`].
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Forces the parallel collection phase on regardless of ant count.
+    ///
+    /// Runs with at least [`PARALLEL_ANT_THRESHOLD`] ants already switch to it automatically.
+    ///
+    /// Note this breaks bit-for-bit replay across modes: the parallel path
+    /// gives each ant its own RNG seeded from `(seed, ant_id, step_count)`
+    /// rather than drawing from the serial path's single shared `SmallRng`,
+    /// so the same `--seed` can produce different outcomes depending on
+    /// whether this flag (or the ant-count threshold) puts the run on the
+    /// parallel path. A seed still replays one specific path bit-for-bit;
+    /// it just isn't interchangeable between the two paths.
+    pub fn set_parallel(&mut self, parallel: bool) {
+        self.parallel = parallel;
+    }
+
+    /// Returns whether the parallel collection phase will run, either
+    /// because it was forced via [`Self::set_parallel`] or because the ant
+    /// count crosses [`PARALLEL_ANT_THRESHOLD`] on its own.
+    pub fn is_parallel_active(&self) -> bool {
+        self.parallel || self.ants.len() >= PARALLEL_ANT_THRESHOLD
+    }
+
     /// Runs the simulation until completion.
     /// 
     /// The simulation ends when:
@@ -156,32 +242,59 @@ impl Simulation {
         self.colonies_to_destroy.clear();
         self.ants_to_kill.clear();
 
-        // Single pass: collect moves and fights
-        for ant_id in 0..self.ants.len() {
-            if let Some(colony_idx) = self.ants[ant_id].colony_idx {
-                if let Some(direction) = self.colonies[colony_idx].get_random_direction(&mut self.rng) {
-                    if let Some(target_idx) = self.colonies[colony_idx].get_target_colony(&direction) {
-                        if !self.destroyed_colonies[target_idx] {
-                            let target_colony = &self.colonies[target_idx];
-                            if target_colony.get_ant().is_none() {
-                                self.moves_to_make.push((ant_id, colony_idx, target_idx));
-                            } else {
-                                // Fight detected
-                                self.colonies_to_destroy.push(target_idx);
-                                self.ants_to_kill.push(ant_id);
-                                self.ants_to_kill.push(target_colony.get_ant().unwrap());
-                                
-                                if self.debug {
-                                    println!("{} has been destroyed by ant {} and ant {}!", 
-                                        target_colony.name, ant_id, target_colony.get_ant().unwrap());
-                                }
-                            }
-                        }
+        // Read phase: collect each active ant's move or fight. This only reads
+        // colony/ant state, so it parallelizes over ant indices on large maps;
+        // the mutating destroy/kill/move application below stays sequential.
+        let outcomes: Vec<AntOutcome> = if self.is_parallel_active() {
+            (0..self.ants.len())
+                .into_par_iter()
+                .filter_map(|ant_id| self.collect_ant_outcome_parallel(ant_id))
+                .collect()
+        } else {
+            (0..self.ants.len())
+                .filter_map(|ant_id| self.collect_ant_outcome_serial(ant_id))
+                .collect()
+        };
+
+        for outcome in outcomes {
+            match outcome {
+                AntOutcome::Move(ant_id, from_idx, to_idx) => {
+                    self.moves_to_make.push((ant_id, from_idx, to_idx));
+                }
+                AntOutcome::Fight(target_idx, ant_id, resident_id) => {
+                    self.colonies_to_destroy.push(target_idx);
+                    self.ants_to_kill.push(ant_id);
+                    self.ants_to_kill.push(resident_id);
+
+                    if self.debug {
+                        println!("{} has been destroyed by ant {} and ant {}!",
+                            self.colonies[target_idx].name, ant_id, resident_id);
                     }
                 }
             }
         }
 
+        // Detect simultaneous arrivals: two or more ants targeting the same
+        // empty colony in this step is a fight too, exactly like a resident
+        // ant being there already. A BTreeMap keeps iteration ordered by
+        // colony index, so a replayed run always destroys colonies and
+        // kills ants in the same order under the same `--seed`.
+        let mut arrivals: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for &(ant_id, _, to_idx) in &self.moves_to_make {
+            arrivals.entry(to_idx).or_default().push(ant_id);
+        }
+        for (to_idx, ant_ids) in &arrivals {
+            if ant_ids.len() > 1 {
+                self.colonies_to_destroy.push(*to_idx);
+                self.ants_to_kill.extend(ant_ids);
+
+                if self.debug {
+                    println!("{} has been destroyed by a collision of ants {:?}!",
+                        self.colonies[*to_idx].name, ant_ids);
+                }
+            }
+        }
+
         // Process fights and moves in a single pass
         for colony_idx in &self.colonies_to_destroy {
             self.destroyed_colonies[*colony_idx] = true;
@@ -212,6 +325,104 @@ impl Simulation {
         Ok(())
     }
 
+    /// Evaluates one ant's move using the simulation's shared RNG. Used on
+    /// the serial path, where ants are collected one at a time.
+    fn collect_ant_outcome_serial(&mut self, ant_id: usize) -> Option<AntOutcome> {
+        let colony_idx = self.ants[ant_id].colony_idx?;
+        let direction = match self.strategy {
+            MovementStrategy::Random => self.colonies[colony_idx].get_random_direction(&mut self.rng),
+            MovementStrategy::Pursuit => self
+                .pursuit_direction(ant_id, colony_idx)
+                .or_else(|| self.colonies[colony_idx].get_random_direction(&mut self.rng)),
+        }?;
+        self.resolve_outcome(ant_id, colony_idx, direction)
+    }
+
+    /// Evaluates one ant's move using a per-ant RNG derived from the
+    /// simulation's seed. Used on the parallel path, where ants are
+    /// collected concurrently and so cannot share one mutable RNG.
+    fn collect_ant_outcome_parallel(&self, ant_id: usize) -> Option<AntOutcome> {
+        let colony_idx = self.ants[ant_id].colony_idx?;
+        let mut local_rng = SmallRng::seed_from_u64(
+            self.seed ^ (ant_id as u64) ^ ((self.step_count as u64) << 32),
+        );
+        let direction = match self.strategy {
+            MovementStrategy::Random => self.colonies[colony_idx].get_random_direction(&mut local_rng),
+            MovementStrategy::Pursuit => self
+                .pursuit_direction(ant_id, colony_idx)
+                .or_else(|| self.colonies[colony_idx].get_random_direction(&mut local_rng)),
+        }?;
+        self.resolve_outcome(ant_id, colony_idx, direction)
+    }
+
+    /// Turns a chosen direction into a `Move` or `Fight` outcome, or `None`
+    /// if the target colony has already been destroyed.
+    fn resolve_outcome(&self, ant_id: usize, colony_idx: usize, direction: Direction) -> Option<AntOutcome> {
+        let target_idx = self.colonies[colony_idx].get_target_colony(&direction)?;
+        if self.destroyed_colonies[target_idx] {
+            return None;
+        }
+        match self.colonies[target_idx].get_ant() {
+            None => Some(AntOutcome::Move(ant_id, colony_idx, target_idx)),
+            Some(resident_id) => Some(AntOutcome::Fight(target_idx, ant_id, resident_id)),
+        }
+    }
+
+    /// Finds the first tunnel to take from `colony_idx` to reach the nearest
+    /// colony (other than `colony_idx` itself) occupied by an ant other than
+    /// `ant_id`, via breadth-first search over non-destroyed colonies.
+    ///
+    /// Returns `None` if no such colony is reachable.
+    fn pursuit_direction(&self, ant_id: usize, colony_idx: usize) -> Option<Direction> {
+        let mut visited = vec![false; self.colonies.len()];
+        let mut parent: Vec<Option<(usize, Direction)>> = vec![None; self.colonies.len()];
+        let mut frontier = VecDeque::new();
+
+        visited[colony_idx] = true;
+        frontier.push_back(colony_idx);
+
+        while let Some(current) = frontier.pop_front() {
+            if current != colony_idx {
+                if let Some(other_ant) = self.colonies[current].get_ant() {
+                    if other_ant != ant_id {
+                        return Some(self.first_hop_direction(&parent, colony_idx, current));
+                    }
+                }
+            }
+
+            for direction in [Direction::North, Direction::South, Direction::East, Direction::West] {
+                if let Some(target_idx) = self.colonies[current].get_target_colony(&direction) {
+                    if self.destroyed_colonies[target_idx] || visited[target_idx] {
+                        continue;
+                    }
+                    visited[target_idx] = true;
+                    parent[target_idx] = Some((current, direction));
+                    frontier.push_back(target_idx);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Walks `parent` back from `goal` to `start` and returns the direction
+    /// taken on the very first hop out of `start`.
+    fn first_hop_direction(
+        &self,
+        parent: &[Option<(usize, Direction)>],
+        start: usize,
+        goal: usize,
+    ) -> Direction {
+        let mut node = goal;
+        loop {
+            let (prev, direction) = parent[node].expect("goal was reached so a parent chain to start must exist");
+            if prev == start {
+                return direction;
+            }
+            node = prev;
+        }
+    }
+
     /// Checks if any ants are still active in the simulation.
     /// 
     /// An ant is considered active if:
@@ -251,4 +462,124 @@ impl Simulation {
             }
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a `Simulation` directly from hand-placed colonies/ants,
+    /// bypassing `new`'s random placement so a test can fix exactly who
+    /// starts where.
+    fn make_simulation(colonies: Vec<Colony>, ants: Vec<Ant>) -> Simulation {
+        let destroyed_colonies = vec![false; colonies.len()];
+        Simulation {
+            destroyed_colonies,
+            moves_to_make: Vec::new(),
+            colonies_to_destroy: Vec::new(),
+            ants_to_kill: Vec::new(),
+            step_count: 0,
+            max_moves: MAX_MOVES,
+            max_steps: MAX_STEPS,
+            debug: false,
+            rng: SmallRng::seed_from_u64(0),
+            strategy: MovementStrategy::Random,
+            seed: 0,
+            parallel: false,
+            colonies,
+            ants,
+        }
+    }
+
+    #[test]
+    fn simultaneous_arrivals_into_an_empty_colony_are_a_fight() {
+        // Colonies A and B each have exactly one tunnel, both leading into
+        // the same empty colony C, so whichever way the RNG breaks, ant 0
+        // and ant 1 have no choice but to collide in C this step.
+        let mut colony_a = Colony::new("A".to_string());
+        colony_a.add_tunnel(Direction::North, 2);
+        colony_a.set_ant(Some(0));
+        let mut colony_b = Colony::new("B".to_string());
+        colony_b.add_tunnel(Direction::South, 2);
+        colony_b.set_ant(Some(1));
+        let colony_c = Colony::new("C".to_string());
+
+        let ants = vec![
+            Ant { moves: 0, colony_idx: Some(0) },
+            Ant { moves: 0, colony_idx: Some(1) },
+        ];
+
+        let mut sim = make_simulation(vec![colony_a, colony_b, colony_c], ants);
+        sim.step().expect("step should succeed");
+
+        assert!(sim.destroyed_colonies[2], "colony C should be destroyed by the collision");
+        assert!(sim.colonies[2].is_destroyed());
+        assert!(sim.ants[0].colony_idx.is_none(), "ant 0 should have died in the collision");
+        assert!(sim.ants[1].colony_idx.is_none(), "ant 1 should have died in the collision");
+    }
+
+    #[test]
+    fn a_single_ant_moving_into_an_empty_colony_still_moves() {
+        // Companion sanity check: one ant taking its only tunnel into an
+        // empty colony is a normal move, not a collision.
+        let mut colony_a = Colony::new("A".to_string());
+        colony_a.add_tunnel(Direction::North, 1);
+        colony_a.set_ant(Some(0));
+        let colony_b = Colony::new("B".to_string());
+
+        let ants = vec![Ant { moves: 0, colony_idx: Some(0) }];
+
+        let mut sim = make_simulation(vec![colony_a, colony_b], ants);
+        sim.step().expect("step should succeed");
+
+        assert_eq!(sim.ants[0].colony_idx, Some(1));
+        assert!(!sim.destroyed_colonies[1]);
+    }
+
+    #[test]
+    fn pursuit_direction_takes_the_first_hop_toward_an_ant_two_colonies_away() {
+        // A -North-> B -North-> C, with ant 0 at A and ant 1 at C. The
+        // shortest path to the nearest other ant is A -> B -> C, so the
+        // first hop out of A should be North.
+        let mut colony_a = Colony::new("A".to_string());
+        colony_a.add_tunnel(Direction::North, 1);
+        colony_a.set_ant(Some(0));
+        let mut colony_b = Colony::new("B".to_string());
+        colony_b.add_tunnel(Direction::South, 0);
+        colony_b.add_tunnel(Direction::North, 2);
+        let mut colony_c = Colony::new("C".to_string());
+        colony_c.add_tunnel(Direction::South, 1);
+        colony_c.set_ant(Some(1));
+
+        let ants = vec![
+            Ant { moves: 0, colony_idx: Some(0) },
+            Ant { moves: 0, colony_idx: Some(2) },
+        ];
+
+        let sim = make_simulation(vec![colony_a, colony_b, colony_c], ants);
+
+        assert_eq!(sim.pursuit_direction(0, 0), Some(Direction::North));
+    }
+
+    #[test]
+    fn pursuit_strategy_falls_back_to_random_when_no_other_ant_is_reachable() {
+        // A lone ant with no other ant anywhere on the map has nothing to
+        // pursue, so it should still take its only tunnel via the Random
+        // fallback baked into collect_ant_outcome_serial.
+        let mut colony_a = Colony::new("A".to_string());
+        colony_a.add_tunnel(Direction::North, 1);
+        colony_a.set_ant(Some(0));
+        let colony_b = Colony::new("B".to_string());
+
+        let ants = vec![Ant { moves: 0, colony_idx: Some(0) }];
+
+        let mut sim = make_simulation(vec![colony_a, colony_b], ants);
+        sim.strategy = MovementStrategy::Pursuit;
+
+        assert_eq!(sim.pursuit_direction(0, 0), None);
+
+        sim.step().expect("step should succeed");
+
+        assert_eq!(sim.ants[0].colony_idx, Some(1));
+    }
 } 
\ No newline at end of file